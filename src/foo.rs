@@ -4,6 +4,7 @@ use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
 
+use futures_core::future::FusedFuture;
 use pin_project::pin_project;
 
 use crate::async_util::ready;
@@ -19,6 +20,7 @@ enum State<TFn, TFnArg, TFuture, TOutput> {
     NoAction(Option<TOutput>),
     Pending(Option<(TFn, TFnArg)>),
     Polling(#[pin] TFuture),
+    Done,
 }
 
 #[pin_project]
@@ -59,19 +61,28 @@ where
         let mut state = self.project().state;
 
         match state.as_mut().project() {
-            NoAction(v) => return Poll::Ready(v.take().expect("State::NoAction polled twice")),
+            NoAction(v) => {
+                let v = v.take().expect("State::NoAction polled after completion");
+                state.set(State::Done);
+                return Poll::Ready(v);
+            }
 
             Pending(payload) => {
-                let (f, x) = payload.take().expect("State::Pending polled twice");
+                let (f, x) = payload
+                    .take()
+                    .expect("State::Pending polled after completion");
                 let future = f(x);
                 state.set(State::Polling(future));
             }
 
-            _ => {}
+            Done => return Poll::Pending,
+
+            Polling(_) => {}
         }
 
-        if let Polling(future) = state.project() {
+        if let Polling(future) = state.as_mut().project() {
             let output = ready!(future.poll(cx));
+            state.set(State::Done);
             Poll::Ready(TPollMapper::map(output))
         } else {
             unreachable!()
@@ -79,6 +90,18 @@ where
     }
 }
 
+impl<TFn, TFnArg, TFuture, TPollMapper, TOutput> FusedFuture
+    for Foo<TFn, TFnArg, TFuture, TPollMapper, TOutput>
+where
+    TFn: FnOnce(TFnArg) -> TFuture,
+    TFuture: Future,
+    TPollMapper: PollMapper<In = TFuture::Output, Out = TOutput>,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+}
+
 pub struct MapSome<T>(PhantomData<fn() -> T>);
 
 impl<T> PollMapper for MapSome<T> {
@@ -103,6 +126,18 @@ impl<T, E> PollMapper for MapOk<T, E> {
     }
 }
 
+pub struct MapErr<T, F>(PhantomData<fn() -> (T, F)>);
+
+impl<T, F> PollMapper for MapErr<T, F> {
+    type In = F;
+    type Out = Result<T, F>;
+
+    #[inline(always)]
+    fn map(i: Self::In) -> Self::Out {
+        Err(i)
+    }
+}
+
 pub struct Id<T>(PhantomData<fn() -> T>);
 
 impl<T> PollMapper for Id<T> {
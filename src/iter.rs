@@ -2,6 +2,7 @@ use crate::async_util::{ready, OptionPinned};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
+use futures_core::stream::FusedStream;
 use futures_core::Stream;
 use pin_project::pin_project;
 
@@ -36,6 +37,96 @@ pub trait AsyncMapExt<T>: Sized {
     where
         TFn: FnMut(T) -> TFuture,
         TFuture: Future<Output = U>;
+
+    /// Basically same as [`Iterator::filter`], but it accepts closure that returns
+    /// [`Future`] and creates new [`Stream`] instead of [`Iterator`].
+    ///
+    /// [`Iterator`]: core::iter::Iterator
+    /// [`Iterator::filter`]: core::iter::Iterator::filter
+    /// [`Future`]: core::future::Future
+    /// [`Stream`]: futures_core::Stream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// assert_eq!(
+    ///     vec![1, 2, 3]
+    ///         .into_iter()
+    ///         .async_filter(|&x| async move { x % 2 == 0 })
+    ///         .collect::<Vec<_>>()
+    ///         .await,
+    ///     vec![2],
+    /// );
+    /// # }
+    /// ```
+    fn async_filter<TFn, TFuture>(self, f: TFn) -> AsyncFilter<Self, TFn, TFuture, T>
+    where
+        TFn: FnMut(&T) -> TFuture,
+        TFuture: Future<Output = bool>;
+
+    /// Basically same as [`Iterator::filter_map`], but it accepts closure that returns
+    /// [`Future`] and creates new [`Stream`] instead of [`Iterator`].
+    ///
+    /// [`Iterator`]: core::iter::Iterator
+    /// [`Iterator::filter_map`]: core::iter::Iterator::filter_map
+    /// [`Future`]: core::future::Future
+    /// [`Stream`]: futures_core::Stream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// assert_eq!(
+    ///     vec![1, 2, 3]
+    ///         .into_iter()
+    ///         .async_filter_map(|x| async move { (x % 2 == 0).then(|| x * 10) })
+    ///         .collect::<Vec<_>>()
+    ///         .await,
+    ///     vec![20],
+    /// );
+    /// # }
+    /// ```
+    fn async_filter_map<TFn, TFuture, U>(self, f: TFn) -> AsyncFilterMap<Self, TFn, TFuture>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = Option<U>>;
+
+    /// Basically same as [`Iterator::fold`], but it accepts closure that returns
+    /// [`Future`] and returns a [`Future`] instead of running to completion
+    /// immediately.
+    ///
+    /// [`Iterator::fold`]: core::iter::Iterator::fold
+    /// [`Future`]: core::future::Future
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     vec![1, 2, 3]
+    ///         .into_iter()
+    ///         .async_fold(0, |acc, x| async move { acc + x })
+    ///         .await,
+    ///     6,
+    /// );
+    /// # }
+    /// ```
+    fn async_fold<Acc, TFn, TFuture>(self, init: Acc, f: TFn) -> AsyncFold<Self, TFn, TFuture, Acc>
+    where
+        TFn: FnMut(Acc, T) -> TFuture,
+        TFuture: Future<Output = Acc>;
 }
 
 impl<TIter, T> AsyncMapExt<T> for TIter
@@ -49,6 +140,30 @@ where
     {
         AsyncMap::new(self, f)
     }
+
+    fn async_filter<TFn, TFuture>(self, f: TFn) -> AsyncFilter<Self, TFn, TFuture, T>
+    where
+        TFn: FnMut(&T) -> TFuture,
+        TFuture: Future<Output = bool>,
+    {
+        AsyncFilter::new(self, f)
+    }
+
+    fn async_filter_map<TFn, TFuture, U>(self, f: TFn) -> AsyncFilterMap<Self, TFn, TFuture>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = Option<U>>,
+    {
+        AsyncFilterMap::new(self, f)
+    }
+
+    fn async_fold<Acc, TFn, TFuture>(self, init: Acc, f: TFn) -> AsyncFold<Self, TFn, TFuture, Acc>
+    where
+        TFn: FnMut(Acc, T) -> TFuture,
+        TFuture: Future<Output = Acc>,
+    {
+        AsyncFold::new(self, init, f)
+    }
 }
 
 #[doc(hidden)]
@@ -58,6 +173,7 @@ pub struct AsyncMap<TIter, TFn, TFuture> {
     mapper_future: OptionPinned<TFuture>,
     mapper: TFn,
     iter: TIter,
+    done: bool,
 }
 
 impl<TIter, TFn, TFuture> AsyncMap<TIter, TFn, TFuture> {
@@ -66,6 +182,7 @@ impl<TIter, TFn, TFuture> AsyncMap<TIter, TFn, TFuture> {
             mapper_future: OptionPinned::None,
             mapper: f,
             iter,
+            done: false,
         }
     }
 }
@@ -84,10 +201,17 @@ where
     ) -> Poll<Option<<Self as Stream>::Item>> {
         let mut me = self.project();
 
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
         if me.mapper_future.is_none() {
             let item = match me.iter.next() {
                 Some(x) => x,
-                None => return Poll::Ready(None),
+                None => {
+                    *me.done = true;
+                    return Poll::Ready(None);
+                }
             };
 
             let future = (me.mapper)(item);
@@ -103,6 +227,186 @@ where
     }
 }
 
+impl<TIter, TFn, T, U, TFuture> FusedStream for AsyncMap<TIter, TFn, TFuture>
+where
+    TFn: FnMut(T) -> TFuture,
+    TIter: Iterator<Item = T>,
+    TFuture: Future<Output = U>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFilter<TIter, TFn, TFuture, T> {
+    #[pin]
+    predicate_future: OptionPinned<TFuture>,
+    pending_item: Option<T>,
+    predicate: TFn,
+    iter: TIter,
+}
+
+impl<TIter, TFn, TFuture, T> AsyncFilter<TIter, TFn, TFuture, T> {
+    fn new(iter: TIter, f: TFn) -> Self {
+        Self {
+            predicate_future: OptionPinned::None,
+            pending_item: None,
+            predicate: f,
+            iter,
+        }
+    }
+}
+
+impl<TIter, TFn, T, TFuture> Stream for AsyncFilter<TIter, TFn, TFuture, T>
+where
+    TFn: FnMut(&T) -> TFuture,
+    TIter: Iterator<Item = T>,
+    TFuture: Future<Output = bool>,
+{
+    type Item = T;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if me.predicate_future.is_none() {
+                let item = match me.iter.next() {
+                    Some(x) => x,
+                    None => return Poll::Ready(None),
+                };
+
+                let future = (me.predicate)(&item);
+                me.predicate_future.set(OptionPinned::Some(future));
+                *me.pending_item = Some(item);
+            }
+
+            let future = me.predicate_future.as_mut().project().unwrap();
+            let keep = ready!(future.poll(cx));
+
+            me.predicate_future.set(OptionPinned::None);
+            let item = me.pending_item.take().expect("pending item must be set");
+
+            if keep {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFilterMap<TIter, TFn, TFuture> {
+    #[pin]
+    mapper_future: OptionPinned<TFuture>,
+    mapper: TFn,
+    iter: TIter,
+}
+
+impl<TIter, TFn, TFuture> AsyncFilterMap<TIter, TFn, TFuture> {
+    fn new(iter: TIter, f: TFn) -> Self {
+        Self {
+            mapper_future: OptionPinned::None,
+            mapper: f,
+            iter,
+        }
+    }
+}
+
+impl<TIter, TFn, T, U, TFuture> Stream for AsyncFilterMap<TIter, TFn, TFuture>
+where
+    TFn: FnMut(T) -> TFuture,
+    TIter: Iterator<Item = T>,
+    TFuture: Future<Output = Option<U>>,
+{
+    type Item = U;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if me.mapper_future.is_none() {
+                let item = match me.iter.next() {
+                    Some(x) => x,
+                    None => return Poll::Ready(None),
+                };
+
+                let future = (me.mapper)(item);
+                me.mapper_future.set(OptionPinned::Some(future));
+            }
+
+            let future = me.mapper_future.as_mut().project().unwrap();
+            let output = ready!(future.poll(cx));
+
+            me.mapper_future.set(OptionPinned::None);
+
+            if let Some(output) = output {
+                return Poll::Ready(Some(output));
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFold<TIter, TFn, TFuture, Acc> {
+    #[pin]
+    fold_future: OptionPinned<TFuture>,
+    acc: Option<Acc>,
+    folder: TFn,
+    iter: TIter,
+}
+
+impl<TIter, TFn, TFuture, Acc> AsyncFold<TIter, TFn, TFuture, Acc> {
+    fn new(iter: TIter, init: Acc, f: TFn) -> Self {
+        Self {
+            fold_future: OptionPinned::None,
+            acc: Some(init),
+            folder: f,
+            iter,
+        }
+    }
+}
+
+impl<TIter, TFn, T, Acc, TFuture> Future for AsyncFold<TIter, TFn, TFuture, Acc>
+where
+    TFn: FnMut(Acc, T) -> TFuture,
+    TIter: Iterator<Item = T>,
+    TFuture: Future<Output = Acc>,
+{
+    type Output = Acc;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut me = self.project();
+
+        loop {
+            if me.fold_future.is_none() {
+                let item = match me.iter.next() {
+                    Some(x) => x,
+                    None => return Poll::Ready(me.acc.take().expect("acc must be set")),
+                };
+
+                let acc = me.acc.take().expect("acc must be set");
+                let future = (me.folder)(acc, item);
+                me.fold_future.set(OptionPinned::Some(future));
+            }
+
+            let future = me.fold_future.as_mut().project().unwrap();
+            let acc = ready!(future.poll(cx));
+
+            me.fold_future.set(OptionPinned::None);
+            *me.acc = Some(acc);
+        }
+    }
+}
+
 #[cfg(test)]
 #[tokio::test]
 async fn test() {
@@ -117,3 +421,45 @@ async fn test() {
         vec![2, 3],
     );
 }
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_fold() {
+    assert_eq!(
+        vec![1, 2, 3]
+            .into_iter()
+            .async_fold(0, |acc, x| async move { acc + x })
+            .await,
+        6,
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_filter() {
+    use tokio_stream::StreamExt;
+
+    assert_eq!(
+        vec![1, 2, 3]
+            .into_iter()
+            .async_filter(|&x| async move { x % 2 == 0 })
+            .collect::<Vec<_>>()
+            .await,
+        vec![2],
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_filter_map() {
+    use tokio_stream::StreamExt;
+
+    assert_eq!(
+        vec![1, 2, 3]
+            .into_iter()
+            .async_filter_map(|x| async move { (x % 2 == 0).then(|| x * 10) })
+            .collect::<Vec<_>>()
+            .await,
+        vec![20],
+    );
+}
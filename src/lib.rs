@@ -24,6 +24,9 @@
 //! # }
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod async_util;
 mod foo;
 pub mod iter;
@@ -74,6 +74,66 @@ pub trait AsyncMapExt<T> {
     where
         TFn: FnOnce(T) -> TFuture,
         TFuture: Future<Output = Option<U>>;
+
+    /// Basically same as [`Option::or_else`], but it accepts closure that returns [`Future`]
+    ///
+    /// [`Option::or_else`]: core::option::Option::or_else
+    /// [`Future`]: core::future::Future
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Some(1).async_or_else(|_| async move { Some(2) }).await,
+    ///     Some(1),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     None.async_or_else(|_| async move { Some(2) }).await,
+    ///     Some(2)
+    /// );
+    /// # }
+    /// ```
+    fn async_or_else<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, (), TFuture, Id<TFuture::Output>, TFuture::Output>
+    where
+        TFn: FnOnce(()) -> TFuture,
+        TFuture: Future<Output = Option<T>>;
+
+    /// Basically same as [`Option::unwrap_or_else`], but it accepts closure that returns
+    /// [`Future`]
+    ///
+    /// [`Option::unwrap_or_else`]: core::option::Option::unwrap_or_else
+    /// [`Future`]: core::future::Future
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     Some(1).async_unwrap_or_else(|_| async move { 2 }).await,
+    ///     1,
+    /// );
+    ///
+    /// assert_eq!(None.async_unwrap_or_else(|_| async move { 2 }).await, 2);
+    /// # }
+    /// ```
+    fn async_unwrap_or_else<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, (), TFuture, Id<TFuture::Output>, T>
+    where
+        TFn: FnOnce(()) -> TFuture,
+        TFuture: Future<Output = T>;
 }
 
 impl<T> AsyncMapExt<T> for Option<T> {
@@ -104,6 +164,34 @@ impl<T> AsyncMapExt<T> for Option<T> {
             None => Foo::no_action(None),
         }
     }
+
+    fn async_or_else<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, (), TFuture, Id<TFuture::Output>, TFuture::Output>
+    where
+        TFn: FnOnce(()) -> TFuture,
+        TFuture: Future<Output = Option<T>>,
+    {
+        match self {
+            Some(v) => Foo::no_action(Some(v)),
+            None => Foo::new(f, ()),
+        }
+    }
+
+    fn async_unwrap_or_else<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, (), TFuture, Id<TFuture::Output>, T>
+    where
+        TFn: FnOnce(()) -> TFuture,
+        TFuture: Future<Output = T>,
+    {
+        match self {
+            Some(v) => Foo::no_action(v),
+            None => Foo::new(f, ()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -142,4 +230,31 @@ mod test {
             None
         );
     }
+
+    #[tokio::test]
+    async fn or_else() {
+        assert_eq!(
+            Some(1).async_or_else(|_| async move { Some(2) }).await,
+            Some(1),
+        );
+
+        assert_eq!(
+            Option::<i32>::None
+                .async_or_else(|_| async move { Some(2) })
+                .await,
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn unwrap_or_else() {
+        assert_eq!(Some(1).async_unwrap_or_else(|_| async move { 2 }).await, 1,);
+
+        assert_eq!(
+            Option::<i32>::None
+                .async_unwrap_or_else(|_| async move { 2 })
+                .await,
+            2
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use core::future::Future;
 
-use crate::foo::{Foo, Id, MapOk};
+use crate::foo::{Foo, Id, MapErr, MapOk};
 
 pub trait AsyncMapExt<T, E> {
     /// Basically same as [`Result::map`], but it accepts closure that returns [`Future`]
@@ -78,6 +78,115 @@ pub trait AsyncMapExt<T, E> {
     where
         TFn: FnOnce(T) -> TFuture,
         TFuture: Future<Output = Result<U, E>>;
+
+    /// Basically same as [`Result::map_err`], but it accepts closure that returns [`Future`]
+    ///
+    /// [`Result::map_err`]: core::result::Result::map_err
+    /// [`Future`]: core::future::Future
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// type Result = core::result::Result<i32, i32>;
+    ///
+    /// assert_eq!(
+    ///     Result::Ok(1)
+    ///         .async_map_err(|e: i32| async move { e + 1 })
+    ///         .await,
+    ///     Result::Ok(1),
+    /// );
+    /// assert_eq!(
+    ///     Result::Err(4)
+    ///         .async_map_err(|e: i32| async move { e + 1 })
+    ///         .await,
+    ///     Result::Err(5),
+    /// );
+    /// # }
+    /// ```
+    fn async_map_err<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, E, TFuture, MapErr<T, TFuture::Output>, Result<T, TFuture::Output>>
+    where
+        TFn: FnOnce(E) -> TFuture,
+        TFuture: Future;
+
+    /// Basically same as [`Result::or_else`], but it accepts closure that returns [`Future`]
+    ///
+    /// [`Result::or_else`]: core::result::Result::or_else
+    /// [`Future`]: core::future::Future
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// type Result = core::result::Result<i32, i32>;
+    ///
+    /// assert_eq!(
+    ///     Result::Ok(1)
+    ///         .async_or_else(|e: i32| async move { Result::Ok(e + 1) })
+    ///         .await,
+    ///     Result::Ok(1),
+    /// );
+    /// assert_eq!(
+    ///     Result::Err(4)
+    ///         .async_or_else(|e: i32| async move { Result::Ok(e + 1) })
+    ///         .await,
+    ///     Result::Ok(5),
+    /// );
+    /// # }
+    /// ```
+    fn async_or_else<F, TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, E, TFuture, Id<TFuture::Output>, Result<T, F>>
+    where
+        TFn: FnOnce(E) -> TFuture,
+        TFuture: Future<Output = Result<T, F>>;
+
+    /// Basically same as [`Result::unwrap_or_else`], but it accepts closure that returns
+    /// [`Future`]
+    ///
+    /// [`Result::unwrap_or_else`]: core::result::Result::unwrap_or_else
+    /// [`Future`]: core::future::Future
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// type Result = core::result::Result<i32, i32>;
+    ///
+    /// assert_eq!(
+    ///     Result::Ok(1)
+    ///         .async_unwrap_or_else(|e: i32| async move { e + 1 })
+    ///         .await,
+    ///     1,
+    /// );
+    /// assert_eq!(
+    ///     Result::Err(4)
+    ///         .async_unwrap_or_else(|e: i32| async move { e + 1 })
+    ///         .await,
+    ///     5,
+    /// );
+    /// # }
+    /// ```
+    fn async_unwrap_or_else<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, E, TFuture, Id<TFuture::Output>, T>
+    where
+        TFn: FnOnce(E) -> TFuture,
+        TFuture: Future<Output = T>;
 }
 
 impl<T, E> AsyncMapExt<T, E> for Result<T, E> {
@@ -108,6 +217,48 @@ impl<T, E> AsyncMapExt<T, E> for Result<T, E> {
             Err(e) => Foo::no_action(Err(e)),
         }
     }
+
+    fn async_map_err<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, E, TFuture, MapErr<T, TFuture::Output>, Result<T, TFuture::Output>>
+    where
+        TFn: FnOnce(E) -> TFuture,
+        TFuture: Future,
+    {
+        match self {
+            Ok(v) => Foo::no_action(Ok(v)),
+            Err(e) => Foo::new(f, e),
+        }
+    }
+
+    fn async_or_else<F, TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, E, TFuture, Id<TFuture::Output>, Result<T, F>>
+    where
+        TFn: FnOnce(E) -> TFuture,
+        TFuture: Future<Output = Result<T, F>>,
+    {
+        match self {
+            Ok(v) => Foo::no_action(Ok(v)),
+            Err(e) => Foo::new(f, e),
+        }
+    }
+
+    fn async_unwrap_or_else<TFn, TFuture>(
+        self,
+        f: TFn,
+    ) -> Foo<TFn, E, TFuture, Id<TFuture::Output>, T>
+    where
+        TFn: FnOnce(E) -> TFuture,
+        TFuture: Future<Output = T>,
+    {
+        match self {
+            Ok(v) => Foo::no_action(v),
+            Err(e) => Foo::new(f, e),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -154,4 +305,55 @@ mod test {
             Result::Err(4),
         );
     }
+
+    #[tokio::test]
+    async fn map_err() {
+        assert_eq!(
+            Result::Ok(1)
+                .async_map_err(|e: i32| async move { e + 1 })
+                .await,
+            Result::Ok(1),
+        );
+
+        assert_eq!(
+            Result::Err(4)
+                .async_map_err(|e: i32| async move { e + 1 })
+                .await,
+            Result::Err(5),
+        );
+    }
+
+    #[tokio::test]
+    async fn or_else() {
+        assert_eq!(
+            Result::Ok(1)
+                .async_or_else(|e: i32| async move { Result::Ok(e + 1) })
+                .await,
+            Result::Ok(1),
+        );
+
+        assert_eq!(
+            Result::Err(4)
+                .async_or_else(|e: i32| async move { Result::Ok(e + 1) })
+                .await,
+            Result::Ok(5),
+        );
+    }
+
+    #[tokio::test]
+    async fn unwrap_or_else() {
+        assert_eq!(
+            Result::Ok(1)
+                .async_unwrap_or_else(|e: i32| async move { e + 1 })
+                .await,
+            1,
+        );
+
+        assert_eq!(
+            Result::Err(4)
+                .async_unwrap_or_else(|e: i32| async move { e + 1 })
+                .await,
+            5,
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::async_util::{ready, OptionPinned};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
+use futures_core::stream::FusedStream;
 use futures_core::Stream;
 use pin_project::pin_project;
 
@@ -33,6 +34,198 @@ pub trait AsyncMapExt<T>: Sized {
     where
         TFn: FnMut(T) -> TFuture,
         TFuture: Future<Output = U>;
+
+    /// Like [`async_map`], but runs up to `n` mapper futures concurrently and yields
+    /// their outputs in the same order the source items arrived in. This mirrors
+    /// [`StreamExt::buffered`].
+    ///
+    /// [`async_map`]: Self::async_map
+    /// [`StreamExt::buffered`]: https://docs.rs/futures-util/0.3/futures_util/stream/trait.StreamExt.html#method.buffered
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// assert_eq!(
+    ///     tokio_stream::iter(vec![1, 2, 3])
+    ///         .async_map_buffered(|x| async move { x + 1 }, 2)
+    ///         .collect::<Vec<_>>()
+    ///         .await,
+    ///     vec![2, 3, 4],
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn async_map_buffered<TFn, TFuture, U>(
+        self,
+        f: TFn,
+        n: usize,
+    ) -> AsyncMapBuffered<Self, TFn, TFuture, U>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = U>;
+
+    /// Like [`async_map_buffered`], but yields outputs in whatever order their mapper
+    /// futures happen to resolve, rather than source order. This mirrors
+    /// [`StreamExt::buffer_unordered`].
+    ///
+    /// [`async_map_buffered`]: Self::async_map_buffered
+    /// [`StreamExt::buffer_unordered`]: https://docs.rs/futures-util/0.3/futures_util/stream/trait.StreamExt.html#method.buffer_unordered
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// let mut out = tokio_stream::iter(vec![1, 2, 3])
+    ///     .async_map_buffered_unordered(|x| async move { x + 1 }, 2)
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// out.sort_unstable();
+    /// assert_eq!(out, vec![2, 3, 4]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn async_map_buffered_unordered<TFn, TFuture, U>(
+        self,
+        f: TFn,
+        n: usize,
+    ) -> AsyncMapBufferedUnordered<Self, TFn, TFuture, U>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = U>;
+
+    /// Basically same as [`StreamExt::filter`], but it accepts closure that returns
+    /// [`Future`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`StreamExt::filter`]: https://docs.rs/tokio-stream/0.1.9/tokio_stream/trait.StreamExt.html#method.filter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// assert_eq!(
+    ///     tokio_stream::iter(vec![1, 2, 3])
+    ///         .async_filter(|&x| async move { x % 2 == 0 })
+    ///         .collect::<Vec<_>>()
+    ///         .await,
+    ///     vec![2],
+    /// );
+    /// # }
+    /// ```
+    fn async_filter<TFn, TFuture>(self, f: TFn) -> AsyncFilter<Self, TFn, TFuture, T>
+    where
+        TFn: FnMut(&T) -> TFuture,
+        TFuture: Future<Output = bool>;
+
+    /// Basically same as [`StreamExt::filter_map`], but it accepts closure that returns
+    /// [`Future`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`StreamExt::filter_map`]: https://docs.rs/tokio-stream/0.1.9/tokio_stream/trait.StreamExt.html#method.filter_map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// assert_eq!(
+    ///     tokio_stream::iter(vec![1, 2, 3])
+    ///         .async_filter_map(|x| async move { (x % 2 == 0).then(|| x * 10) })
+    ///         .collect::<Vec<_>>()
+    ///         .await,
+    ///     vec![20],
+    /// );
+    /// # }
+    /// ```
+    fn async_filter_map<TFn, TFuture, U>(self, f: TFn) -> AsyncFilterMap<Self, TFn, TFuture>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = Option<U>>;
+
+    /// Basically same as [`StreamExt::fold`], but it accepts closure that returns
+    /// [`Future`].
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`StreamExt::fold`]: https://docs.rs/tokio-stream/0.1.9/tokio_stream/trait.StreamExt.html#method.fold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     tokio_stream::iter(vec![1, 2, 3])
+    ///         .async_fold(0, |acc, x| async move { acc + x })
+    ///         .await,
+    ///     6,
+    /// );
+    /// # }
+    /// ```
+    fn async_fold<Acc, TFn, TFuture>(self, init: Acc, f: TFn) -> AsyncFold<Self, TFn, TFuture, Acc>
+    where
+        TFn: FnMut(Acc, T) -> TFuture,
+        TFuture: Future<Output = Acc>;
+
+    /// Basically same as [`StreamExt::flat_map`], but it accepts closure that returns
+    /// [`Future`] instead of directly returning the inner [`Stream`]: the mapper future
+    /// is awaited first to obtain the inner stream, which is then drained to completion
+    /// before the next source item is pulled.
+    ///
+    /// [`Future`]: core::future::Future
+    /// [`Stream`]: futures_core::Stream
+    /// [`StreamExt::flat_map`]: https://docs.rs/futures-util/0.3/futures_util/stream/trait.StreamExt.html#method.flat_map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use async_hofs::prelude::*;
+    /// use tokio_stream::StreamExt; // for .collect
+    ///
+    /// assert_eq!(
+    ///     tokio_stream::iter(vec![1, 2])
+    ///         .async_flat_map(|x| async move { tokio_stream::iter(vec![x, x * 10]) })
+    ///         .collect::<Vec<_>>()
+    ///         .await,
+    ///     vec![1, 10, 2, 20],
+    /// );
+    /// # }
+    /// ```
+    fn async_flat_map<TFn, TFuture, TInner>(
+        self,
+        f: TFn,
+    ) -> AsyncFlatMap<Self, TFn, TFuture, TInner>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = TInner>,
+        TInner: Stream;
 }
 
 impl<TStream, T> AsyncMapExt<T> for TStream
@@ -46,6 +239,68 @@ where
     {
         AsyncMap::new(self, f)
     }
+
+    #[cfg(feature = "alloc")]
+    fn async_map_buffered<TFn, TFuture, U>(
+        self,
+        f: TFn,
+        n: usize,
+    ) -> AsyncMapBuffered<Self, TFn, TFuture, U>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = U>,
+    {
+        AsyncMapBuffered::new(self, f, n)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn async_map_buffered_unordered<TFn, TFuture, U>(
+        self,
+        f: TFn,
+        n: usize,
+    ) -> AsyncMapBufferedUnordered<Self, TFn, TFuture, U>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = U>,
+    {
+        AsyncMapBufferedUnordered::new(self, f, n)
+    }
+
+    fn async_filter<TFn, TFuture>(self, f: TFn) -> AsyncFilter<Self, TFn, TFuture, T>
+    where
+        TFn: FnMut(&T) -> TFuture,
+        TFuture: Future<Output = bool>,
+    {
+        AsyncFilter::new(self, f)
+    }
+
+    fn async_filter_map<TFn, TFuture, U>(self, f: TFn) -> AsyncFilterMap<Self, TFn, TFuture>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = Option<U>>,
+    {
+        AsyncFilterMap::new(self, f)
+    }
+
+    fn async_fold<Acc, TFn, TFuture>(self, init: Acc, f: TFn) -> AsyncFold<Self, TFn, TFuture, Acc>
+    where
+        TFn: FnMut(Acc, T) -> TFuture,
+        TFuture: Future<Output = Acc>,
+    {
+        AsyncFold::new(self, init, f)
+    }
+
+    fn async_flat_map<TFn, TFuture, TInner>(
+        self,
+        f: TFn,
+    ) -> AsyncFlatMap<Self, TFn, TFuture, TInner>
+    where
+        TFn: FnMut(T) -> TFuture,
+        TFuture: Future<Output = TInner>,
+        TInner: Stream,
+    {
+        AsyncFlatMap::new(self, f)
+    }
 }
 
 #[doc(hidden)]
@@ -57,6 +312,7 @@ pub struct AsyncMap<TStream, TFn, TFuture> {
     mapper_future: OptionPinned<TFuture>,
 
     mapper: TFn,
+    done: bool,
 }
 
 impl<TStream, TFn, TFuture> AsyncMap<TStream, TFn, TFuture> {
@@ -65,6 +321,7 @@ impl<TStream, TFn, TFuture> AsyncMap<TStream, TFn, TFuture> {
             stream,
             mapper_future: OptionPinned::None,
             mapper: f,
+            done: false,
         }
     }
 }
@@ -83,10 +340,17 @@ where
     ) -> Poll<Option<<Self as Stream>::Item>> {
         let mut me = self.project();
 
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
         if me.mapper_future.is_none() {
             let item = match ready!(me.stream.poll_next(cx)) {
                 Some(item) => item,
-                None => return Poll::Ready(None),
+                None => {
+                    *me.done = true;
+                    return Poll::Ready(None);
+                }
             };
 
             let future = (me.mapper)(item);
@@ -102,6 +366,462 @@ where
     }
 }
 
+impl<TStream, TFn, T, U, TFuture> FusedStream for AsyncMap<TStream, TFn, TFuture>
+where
+    TFn: FnMut(T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = U>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFilter<TStream, TFn, TFuture, T> {
+    #[pin]
+    stream: TStream,
+    #[pin]
+    predicate_future: OptionPinned<TFuture>,
+    pending_item: Option<T>,
+
+    predicate: TFn,
+}
+
+impl<TStream, TFn, TFuture, T> AsyncFilter<TStream, TFn, TFuture, T> {
+    fn new(stream: TStream, f: TFn) -> Self {
+        Self {
+            stream,
+            predicate_future: OptionPinned::None,
+            pending_item: None,
+            predicate: f,
+        }
+    }
+}
+
+impl<TStream, TFn, T, TFuture> Stream for AsyncFilter<TStream, TFn, TFuture, T>
+where
+    TFn: FnMut(&T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = bool>,
+{
+    type Item = T;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if me.predicate_future.is_none() {
+                let item = match ready!(me.stream.as_mut().poll_next(cx)) {
+                    Some(item) => item,
+                    None => return Poll::Ready(None),
+                };
+
+                let future = (me.predicate)(&item);
+                me.predicate_future.set(OptionPinned::Some(future));
+                *me.pending_item = Some(item);
+            }
+
+            let future = me.predicate_future.as_mut().project().unwrap();
+            let keep = ready!(future.poll(cx));
+
+            me.predicate_future.set(OptionPinned::None);
+            let item = me.pending_item.take().expect("pending item must be set");
+
+            if keep {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFilterMap<TStream, TFn, TFuture> {
+    #[pin]
+    stream: TStream,
+    #[pin]
+    mapper_future: OptionPinned<TFuture>,
+
+    mapper: TFn,
+}
+
+impl<TStream, TFn, TFuture> AsyncFilterMap<TStream, TFn, TFuture> {
+    fn new(stream: TStream, f: TFn) -> Self {
+        Self {
+            stream,
+            mapper_future: OptionPinned::None,
+            mapper: f,
+        }
+    }
+}
+
+impl<TStream, TFn, T, U, TFuture> Stream for AsyncFilterMap<TStream, TFn, TFuture>
+where
+    TFn: FnMut(T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = Option<U>>,
+{
+    type Item = U;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if me.mapper_future.is_none() {
+                let item = match ready!(me.stream.as_mut().poll_next(cx)) {
+                    Some(item) => item,
+                    None => return Poll::Ready(None),
+                };
+
+                let future = (me.mapper)(item);
+                me.mapper_future.set(OptionPinned::Some(future));
+            }
+
+            let future = me.mapper_future.as_mut().project().unwrap();
+            let output = ready!(future.poll(cx));
+
+            me.mapper_future.set(OptionPinned::None);
+
+            if let Some(output) = output {
+                return Poll::Ready(Some(output));
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFold<TStream, TFn, TFuture, Acc> {
+    #[pin]
+    stream: TStream,
+    #[pin]
+    fold_future: OptionPinned<TFuture>,
+    acc: Option<Acc>,
+
+    folder: TFn,
+}
+
+impl<TStream, TFn, TFuture, Acc> AsyncFold<TStream, TFn, TFuture, Acc> {
+    fn new(stream: TStream, init: Acc, f: TFn) -> Self {
+        Self {
+            stream,
+            fold_future: OptionPinned::None,
+            acc: Some(init),
+            folder: f,
+        }
+    }
+}
+
+impl<TStream, TFn, T, Acc, TFuture> Future for AsyncFold<TStream, TFn, TFuture, Acc>
+where
+    TFn: FnMut(Acc, T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = Acc>,
+{
+    type Output = Acc;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut me = self.project();
+
+        loop {
+            if me.fold_future.is_none() {
+                let item = match ready!(me.stream.as_mut().poll_next(cx)) {
+                    Some(item) => item,
+                    None => return Poll::Ready(me.acc.take().expect("acc must be set")),
+                };
+
+                let acc = me.acc.take().expect("acc must be set");
+                let future = (me.folder)(acc, item);
+                me.fold_future.set(OptionPinned::Some(future));
+            }
+
+            let future = me.fold_future.as_mut().project().unwrap();
+            let acc = ready!(future.poll(cx));
+
+            me.fold_future.set(OptionPinned::None);
+            *me.acc = Some(acc);
+        }
+    }
+}
+
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncFlatMap<TStream, TFn, TFuture, TInner> {
+    #[pin]
+    stream: TStream,
+    #[pin]
+    mapper_future: OptionPinned<TFuture>,
+    #[pin]
+    inner_stream: OptionPinned<TInner>,
+
+    mapper: TFn,
+}
+
+impl<TStream, TFn, TFuture, TInner> AsyncFlatMap<TStream, TFn, TFuture, TInner> {
+    fn new(stream: TStream, f: TFn) -> Self {
+        Self {
+            stream,
+            mapper_future: OptionPinned::None,
+            inner_stream: OptionPinned::None,
+            mapper: f,
+        }
+    }
+}
+
+impl<TStream, TFn, T, U, TFuture, TInner> Stream for AsyncFlatMap<TStream, TFn, TFuture, TInner>
+where
+    TFn: FnMut(T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = TInner>,
+    TInner: Stream<Item = U>,
+{
+    type Item = U;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if me.inner_stream.is_some() {
+                let inner = me.inner_stream.as_mut().project().unwrap();
+
+                match ready!(inner.poll_next(cx)) {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => {
+                        me.inner_stream.set(OptionPinned::None);
+                        continue;
+                    }
+                }
+            }
+
+            if me.mapper_future.is_some() {
+                let future = me.mapper_future.as_mut().project().unwrap();
+                let inner = ready!(future.poll(cx));
+
+                me.mapper_future.set(OptionPinned::None);
+                me.inner_stream.set(OptionPinned::Some(inner));
+                continue;
+            }
+
+            let item = match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+
+            let future = (me.mapper)(item);
+            me.mapper_future.set(OptionPinned::Some(future));
+        }
+    }
+}
+
+/// A single mapper future tracked by [`AsyncMapBuffered`]/[`AsyncMapBufferedUnordered`].
+///
+/// Every slot is polled on every `poll_next` call regardless of its position in the
+/// queue, which is what lets up to `limit` mapper futures actually make progress
+/// concurrently. Once a slot resolves it is parked as `Ready` until the stream impl
+/// decides it's this slot's turn to be emitted, so the inner future is never polled
+/// again after returning [`Poll::Ready`].
+///
+/// This deliberately boxes each future and linear-scans the `VecDeque` on every poll,
+/// rather than building an intrusive, per-future-waker set (the shape an internal
+/// `FuturesUnordered`-alike would take). `limit` is caller-supplied and expected to stay
+/// small, so the O(limit) scan and one heap allocation per item are not a concern in
+/// practice, and this keeps both buffered variants free of `unsafe` and of any new
+/// dependency beyond `alloc`.
+#[cfg(feature = "alloc")]
+enum BufferedSlot<TFuture, U> {
+    Pending(Pin<alloc::boxed::Box<TFuture>>),
+    Ready(U),
+}
+
+#[cfg(feature = "alloc")]
+impl<TFuture, U> BufferedSlot<TFuture, U>
+where
+    TFuture: Future<Output = U>,
+{
+    fn poll(&mut self, cx: &mut Context<'_>) {
+        if let Self::Pending(future) = self {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                *self = Self::Ready(output);
+            }
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+
+    fn unwrap_ready(self) -> U {
+        match self {
+            Self::Ready(output) => output,
+            Self::Pending(_) => unreachable!("unwrap_ready called on a pending slot"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncMapBuffered<TStream, TFn, TFuture, U> {
+    #[pin]
+    stream: TStream,
+    mapper: TFn,
+    in_flight: alloc::collections::VecDeque<BufferedSlot<TFuture, U>>,
+    limit: usize,
+    stream_done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<TStream, TFn, TFuture, U> AsyncMapBuffered<TStream, TFn, TFuture, U> {
+    fn new(stream: TStream, f: TFn, n: usize) -> Self {
+        assert!(n > 0, "buffer size must be greater than zero");
+
+        Self {
+            stream,
+            mapper: f,
+            in_flight: alloc::collections::VecDeque::with_capacity(n),
+            limit: n,
+            stream_done: false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TStream, TFn, T, U, TFuture> Stream for AsyncMapBuffered<TStream, TFn, TFuture, U>
+where
+    TFn: FnMut(T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = U>,
+{
+    type Item = U;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        if !*me.stream_done {
+            while me.in_flight.len() < *me.limit {
+                match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        let future = (me.mapper)(item);
+                        me.in_flight
+                            .push_back(BufferedSlot::Pending(alloc::boxed::Box::pin(future)));
+                    }
+                    Poll::Ready(None) => {
+                        *me.stream_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        for slot in me.in_flight.iter_mut() {
+            slot.poll(cx);
+        }
+
+        match me.in_flight.front() {
+            Some(slot) if slot.is_ready() => {
+                let slot = me.in_flight.pop_front().expect("front just checked Some");
+                Poll::Ready(Some(slot.unwrap_ready()))
+            }
+            Some(_) => Poll::Pending,
+            None if *me.stream_done => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[pin_project]
+pub struct AsyncMapBufferedUnordered<TStream, TFn, TFuture, U> {
+    #[pin]
+    stream: TStream,
+    mapper: TFn,
+    in_flight: alloc::collections::VecDeque<BufferedSlot<TFuture, U>>,
+    limit: usize,
+    stream_done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<TStream, TFn, TFuture, U> AsyncMapBufferedUnordered<TStream, TFn, TFuture, U> {
+    fn new(stream: TStream, f: TFn, n: usize) -> Self {
+        assert!(n > 0, "buffer size must be greater than zero");
+
+        Self {
+            stream,
+            mapper: f,
+            in_flight: alloc::collections::VecDeque::with_capacity(n),
+            limit: n,
+            stream_done: false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<TStream, TFn, T, U, TFuture> Stream for AsyncMapBufferedUnordered<TStream, TFn, TFuture, U>
+where
+    TFn: FnMut(T) -> TFuture,
+    TStream: Stream<Item = T>,
+    TFuture: Future<Output = U>,
+{
+    type Item = U;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<<Self as Stream>::Item>> {
+        let mut me = self.project();
+
+        if !*me.stream_done {
+            while me.in_flight.len() < *me.limit {
+                match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        let future = (me.mapper)(item);
+                        me.in_flight
+                            .push_back(BufferedSlot::Pending(alloc::boxed::Box::pin(future)));
+                    }
+                    Poll::Ready(None) => {
+                        *me.stream_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        let mut ready_index = None;
+        for (i, slot) in me.in_flight.iter_mut().enumerate() {
+            slot.poll(cx);
+            if ready_index.is_none() && slot.is_ready() {
+                ready_index = Some(i);
+            }
+        }
+
+        match ready_index {
+            Some(i) => {
+                let slot = me.in_flight.remove(i).expect("index just found by scan");
+                Poll::Ready(Some(slot.unwrap_ready()))
+            }
+            None if me.in_flight.is_empty() && *me.stream_done => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
 #[cfg(test)]
 #[tokio::test]
 async fn test() {
@@ -115,3 +835,113 @@ async fn test() {
         vec![2, 3],
     );
 }
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_filter() {
+    use tokio_stream::StreamExt;
+
+    assert_eq!(
+        tokio_stream::iter(vec![1, 2, 3])
+            .async_filter(|&x| async move { x % 2 == 0 })
+            .collect::<Vec<_>>()
+            .await,
+        vec![2],
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_filter_map() {
+    use tokio_stream::StreamExt;
+
+    assert_eq!(
+        tokio_stream::iter(vec![1, 2, 3])
+            .async_filter_map(|x| async move { (x % 2 == 0).then(|| x * 10) })
+            .collect::<Vec<_>>()
+            .await,
+        vec![20],
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_fold() {
+    assert_eq!(
+        tokio_stream::iter(vec![1, 2, 3])
+            .async_fold(0, |acc, x| async move { acc + x })
+            .await,
+        6,
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_flat_map() {
+    use tokio_stream::StreamExt;
+
+    assert_eq!(
+        tokio_stream::iter(vec![1, 2])
+            .async_flat_map(|x| async move { tokio_stream::iter(vec![x, x * 10]) })
+            .collect::<Vec<_>>()
+            .await,
+        vec![1, 10, 2, 20],
+    );
+}
+
+#[cfg(all(test, feature = "alloc"))]
+#[tokio::test]
+async fn test_buffered() {
+    use tokio_stream::StreamExt;
+
+    assert_eq!(
+        tokio_stream::iter(vec![1, 2, 3])
+            .async_map_buffered(|x| async move { x + 1 }, 2)
+            .collect::<Vec<_>>()
+            .await,
+        vec![2, 3, 4],
+    );
+}
+
+#[cfg(all(test, feature = "alloc"))]
+#[tokio::test(start_paused = true)]
+async fn test_buffered_concurrent_and_ordered() {
+    use std::time::Duration;
+    use tokio_stream::StreamExt;
+
+    let start = tokio::time::Instant::now();
+
+    // Item 0's mapper takes longer than item 1's, so a buggy implementation that only
+    // drives the head of the queue would finish serially (~4s) and a correct one that
+    // drives every in-flight future concurrently finishes in ~3s, the slowest mapper.
+    let out = tokio_stream::iter(vec![0, 1])
+        .async_map_buffered(
+            |x| async move {
+                tokio::time::sleep(Duration::from_secs(if x == 0 { 3 } else { 1 })).await;
+                x
+            },
+            2,
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(out, vec![0, 1], "output must stay in source order");
+    assert!(
+        start.elapsed() < Duration::from_secs(4),
+        "mappers should run concurrently, not serially"
+    );
+}
+
+#[cfg(all(test, feature = "alloc"))]
+#[tokio::test]
+async fn test_buffered_unordered() {
+    use tokio_stream::StreamExt;
+
+    let mut out = tokio_stream::iter(vec![1, 2, 3])
+        .async_map_buffered_unordered(|x| async move { x + 1 }, 2)
+        .collect::<Vec<_>>()
+        .await;
+    out.sort_unstable();
+
+    assert_eq!(out, vec![2, 3, 4]);
+}